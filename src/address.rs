@@ -0,0 +1,115 @@
+//! Checksummed, human-readable address encoding for ML-DSA public keys
+//!
+//! Inspired by sp-core's SS58: since ML-DSA public keys are 1-2.5 KB, an
+//! address is a short, shareable stand-in. A 32-byte account id is derived
+//! as `Blake2b-256(public_key)`, prefixed with a configurable network byte,
+//! checksummed with the first two bytes of
+//! `Blake2b-512(b"ML-DSA-SS58" || prefix || account_id)`, and the whole
+//! `prefix || account_id || checksum` payload is base58-encoded.
+
+use blake2::Blake2b512;
+use blake2::digest::{Digest, consts::U32};
+
+use crate::{Error, Result};
+
+type Blake2b256 = blake2::Blake2b<U32>;
+
+const ADDRESS_CONTEXT: &[u8] = b"ML-DSA-SS58";
+const ADDRESS_LEN: usize = 1 + 32 + 2;
+
+fn account_id(public_key: &[u8]) -> [u8; 32] {
+    let digest = Blake2b256::digest(public_key);
+    let mut out = [0u8; 32];
+    out.copy_from_slice(&digest);
+    out
+}
+
+fn checksum(prefix: u8, account_id: &[u8; 32]) -> [u8; 2] {
+    let mut hasher = Blake2b512::new();
+    hasher.update(ADDRESS_CONTEXT);
+    hasher.update([prefix]);
+    hasher.update(account_id);
+    let digest = hasher.finalize();
+    [digest[0], digest[1]]
+}
+
+/// Encode a public key as a checksummed, network-tagged base58 address
+pub fn encode_address(prefix: u8, public_key: &[u8]) -> String {
+    let account_id = account_id(public_key);
+    let checksum = checksum(prefix, &account_id);
+
+    let mut payload = Vec::with_capacity(ADDRESS_LEN);
+    payload.push(prefix);
+    payload.extend_from_slice(&account_id);
+    payload.extend_from_slice(&checksum);
+
+    bs58::encode(payload).into_string()
+}
+
+/// Decode and validate an address produced by [`encode_address`]
+///
+/// Returns the network prefix and 32-byte account id, or
+/// [`Error::InvalidAddress`] if the base58 or checksum is invalid.
+pub fn decode_address(address: &str) -> Result<(u8, [u8; 32])> {
+    let bytes = bs58::decode(address)
+        .into_vec()
+        .map_err(|e| Error::InvalidAddress(format!("invalid base58: {e}")))?;
+
+    if bytes.len() != ADDRESS_LEN {
+        return Err(Error::InvalidAddress(format!(
+            "expected {ADDRESS_LEN} bytes, got {}",
+            bytes.len()
+        )));
+    }
+
+    let prefix = bytes[0];
+    let mut id = [0u8; 32];
+    id.copy_from_slice(&bytes[1..33]);
+    let provided_checksum = &bytes[33..35];
+
+    if provided_checksum != checksum(prefix, &id) {
+        return Err(Error::InvalidAddress(
+            "checksum does not match prefix and account id".to_string(),
+        ));
+    }
+
+    Ok((prefix, id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_decode_roundtrip() {
+        let public_key = vec![7u8; 1312];
+        let address = encode_address(42, &public_key);
+
+        let (prefix, id) = decode_address(&address).unwrap();
+        assert_eq!(prefix, 42);
+        assert_eq!(id, account_id(&public_key));
+    }
+
+    #[test]
+    fn test_different_prefixes_different_addresses() {
+        let public_key = vec![7u8; 1312];
+        let address_a = encode_address(0, &public_key);
+        let address_b = encode_address(1, &public_key);
+        assert_ne!(address_a, address_b);
+    }
+
+    #[test]
+    fn test_decode_rejects_corrupt_checksum() {
+        let public_key = vec![7u8; 1312];
+        let mut address = encode_address(42, &public_key);
+        address.pop();
+        address.push(if address.ends_with('1') { '2' } else { '1' });
+
+        assert!(decode_address(&address).is_err());
+    }
+
+    #[test]
+    fn test_decode_rejects_invalid_base58() {
+        assert!(decode_address("not-valid-base58-0OIl").is_err());
+    }
+}
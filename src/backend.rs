@@ -0,0 +1,130 @@
+//! Pluggable cryptographic backend for ML-DSA operations
+//!
+//! `MlDsaKeyPair::sign`/`verify` default to [`RustCryptoBackend`], but any
+//! implementation (pqcrypto, liboqs, a hardware token) can be dropped in by
+//! implementing [`Backend`] and calling [`MlDsaKeyPair::sign_with`]/
+//! [`verify_with`](MlDsaKeyPair::verify_with) instead - mirroring how
+//! `secp256k1` abstracts signing/verification capability behind a trait
+//! rather than a single hardcoded implementation.
+
+use crate::{MlDsaKeyPair, MlDsaLevel, MlDsaSignature, Result};
+
+/// A cryptographic backend capable of ML-DSA key generation, signing, and verification
+pub trait Backend {
+    /// Generate a keypair for `level` from a 32-byte seed
+    fn generate_keypair(&self, level: MlDsaLevel, seed: &[u8; 32]) -> Result<MlDsaKeyPair>;
+
+    /// Sign `message` with `keypair`
+    fn sign(&self, keypair: &MlDsaKeyPair, message: &[u8]) -> Result<MlDsaSignature>;
+
+    /// Verify `signature` over `message` against `public_key`
+    fn verify(
+        &self,
+        public_key: &[u8],
+        level: MlDsaLevel,
+        message: &[u8],
+        signature: &MlDsaSignature,
+    ) -> Result<bool>;
+}
+
+/// The default [`Backend`], implemented on top of RustCrypto's `ml-dsa` crate
+#[cfg(feature = "rustcrypto")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RustCryptoBackend;
+
+#[cfg(feature = "rustcrypto")]
+impl Backend for RustCryptoBackend {
+    fn generate_keypair(&self, level: MlDsaLevel, seed: &[u8; 32]) -> Result<MlDsaKeyPair> {
+        crate::backend_rustcrypto::generate_keypair(level, seed)
+    }
+
+    fn sign(&self, keypair: &MlDsaKeyPair, message: &[u8]) -> Result<MlDsaSignature> {
+        crate::backend_rustcrypto::sign(keypair, message)
+    }
+
+    fn verify(
+        &self,
+        public_key: &[u8],
+        level: MlDsaLevel,
+        message: &[u8],
+        signature: &MlDsaSignature,
+    ) -> Result<bool> {
+        crate::backend_rustcrypto::verify(public_key, level, message, signature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sha3::{
+        Shake256,
+        digest::{ExtendableOutput, Update, XofReader},
+    };
+
+    /// A non-cryptographic stand-in backend, used only to prove that
+    /// `Backend` is a real extension point and not just a wrapper around
+    /// `backend_rustcrypto`.
+    struct MockBackend;
+
+    fn mock_hash(parts: &[&[u8]], len: usize) -> Vec<u8> {
+        let mut hasher = Shake256::default();
+        for part in parts {
+            hasher.update(part);
+        }
+        let mut out = vec![0u8; len];
+        hasher.finalize_xof().read(&mut out);
+        out
+    }
+
+    impl Backend for MockBackend {
+        fn generate_keypair(&self, level: MlDsaLevel, seed: &[u8; 32]) -> Result<MlDsaKeyPair> {
+            let public_key = mock_hash(&[b"mock-pubkey", seed], level.public_key_size());
+            Ok(MlDsaKeyPair::new(level, *seed, public_key))
+        }
+
+        fn sign(&self, keypair: &MlDsaKeyPair, message: &[u8]) -> Result<MlDsaSignature> {
+            let bytes = mock_hash(
+                &[keypair.public_key(), message],
+                keypair.level().signature_size(),
+            );
+            Ok(MlDsaSignature::new(keypair.level(), bytes))
+        }
+
+        fn verify(
+            &self,
+            public_key: &[u8],
+            level: MlDsaLevel,
+            message: &[u8],
+            signature: &MlDsaSignature,
+        ) -> Result<bool> {
+            let expected = mock_hash(&[public_key, message], level.signature_size());
+            Ok(expected == signature.as_bytes())
+        }
+    }
+
+    #[test]
+    fn test_mock_backend_sign_verify_roundtrip() {
+        let backend = MockBackend;
+        let keypair = backend.generate_keypair(MlDsaLevel::Dsa44, &[5u8; 32]).unwrap();
+
+        let message = b"swap backends freely";
+        let signature = keypair.sign_with(&backend, message).unwrap();
+
+        assert!(keypair.verify_with(&backend, message, &signature).unwrap());
+        assert!(!keypair.verify_with(&backend, b"tampered", &signature).unwrap());
+    }
+
+    #[cfg(feature = "rustcrypto")]
+    #[test]
+    fn test_rustcrypto_backend_matches_default_methods() {
+        let backend = RustCryptoBackend;
+        let keypair = backend.generate_keypair(MlDsaLevel::Dsa44, &[5u8; 32]).unwrap();
+
+        let message = b"default vs explicit backend";
+        let via_backend = keypair.sign_with(&backend, message).unwrap();
+        let via_default = keypair.sign(message).unwrap();
+
+        assert!(keypair.verify(message, &via_backend).unwrap());
+        assert!(keypair.verify_with(&backend, message, &via_default).unwrap());
+    }
+}
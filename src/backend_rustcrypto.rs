@@ -37,26 +37,47 @@ pub fn generate_keypair(level: MlDsaLevel, seed: &[u8; 32]) -> Result<MlDsaKeyPa
 
 /// Sign a message using the keypair's seed
 pub fn sign(keypair: &MlDsaKeyPair, message: &[u8]) -> Result<MlDsaSignature> {
+    sign_with_context(keypair, message, &[])
+}
+
+/// Sign a message bound to an application context string (FIPS 204 ML-DSA.Sign)
+pub fn sign_with_context(
+    keypair: &MlDsaKeyPair,
+    message: &[u8],
+    context: &[u8],
+) -> Result<MlDsaSignature> {
     let seed = keypair.seed();
-    
+
     match keypair.level() {
         MlDsaLevel::Dsa44 => {
             let seed_array: hybrid_array::Array<u8, _> = (*seed).into();
             let kp = MlDsa44::from_seed(&seed_array);
-            let signature = kp.signing_key().sign(message);
-            Ok(MlDsaSignature::new(keypair.level(), signature.to_bytes().to_vec()))
+            let signature = kp.signing_key().sign_with_context(message, context);
+            Ok(MlDsaSignature::new_with_context(
+                keypair.level(),
+                signature.to_bytes().to_vec(),
+                context.len(),
+            ))
         }
         MlDsaLevel::Dsa65 => {
             let seed_array: hybrid_array::Array<u8, _> = (*seed).into();
             let kp = MlDsa65::from_seed(&seed_array);
-            let signature = kp.signing_key().sign(message);
-            Ok(MlDsaSignature::new(keypair.level(), signature.to_bytes().to_vec()))
+            let signature = kp.signing_key().sign_with_context(message, context);
+            Ok(MlDsaSignature::new_with_context(
+                keypair.level(),
+                signature.to_bytes().to_vec(),
+                context.len(),
+            ))
         }
         MlDsaLevel::Dsa87 => {
             let seed_array: hybrid_array::Array<u8, _> = (*seed).into();
             let kp = MlDsa87::from_seed(&seed_array);
-            let signature = kp.signing_key().sign(message);
-            Ok(MlDsaSignature::new(keypair.level(), signature.to_bytes().to_vec()))
+            let signature = kp.signing_key().sign_with_context(message, context);
+            Ok(MlDsaSignature::new_with_context(
+                keypair.level(),
+                signature.to_bytes().to_vec(),
+                context.len(),
+            ))
         }
     }
 }
@@ -67,6 +88,17 @@ pub fn verify(
     level: MlDsaLevel,
     message: &[u8],
     signature: &MlDsaSignature,
+) -> Result<bool> {
+    verify_with_context(public_key, level, message, &[], signature)
+}
+
+/// Verify a signature produced with an application context string
+pub fn verify_with_context(
+    public_key: &[u8],
+    level: MlDsaLevel,
+    message: &[u8],
+    context: &[u8],
+    signature: &MlDsaSignature,
 ) -> Result<bool> {
     // Ensure signature level matches
     if signature.level() != level {
@@ -86,7 +118,7 @@ pub fn verify(
                     public_key.len()
                 )))?;
             let vk = VerifyingKey::<MlDsa44>::decode(&vk_bytes.into());
-            
+
             let sig_bytes: [u8; 2420] = signature.as_bytes().try_into()
                 .map_err(|_| Error::InvalidSignature(format!(
                     "expected {} bytes, got {}",
@@ -95,8 +127,8 @@ pub fn verify(
                 )))?;
             let sig = Signature::<MlDsa44>::decode(&sig_bytes.into())
                 .ok_or_else(|| Error::InvalidSignature("failed to decode signature".to_string()))?;
-            
-            Ok(vk.verify(message, &sig).is_ok())
+
+            Ok(vk.verify_with_context(message, context, &sig).is_ok())
         }
         MlDsaLevel::Dsa65 => {
             let vk_bytes: [u8; 1952] = public_key.try_into()
@@ -106,7 +138,7 @@ pub fn verify(
                     public_key.len()
                 )))?;
             let vk = VerifyingKey::<MlDsa65>::decode(&vk_bytes.into());
-            
+
             let sig_bytes: [u8; 3309] = signature.as_bytes().try_into()
                 .map_err(|_| Error::InvalidSignature(format!(
                     "expected {} bytes, got {}",
@@ -115,8 +147,8 @@ pub fn verify(
                 )))?;
             let sig = Signature::<MlDsa65>::decode(&sig_bytes.into())
                 .ok_or_else(|| Error::InvalidSignature("failed to decode signature".to_string()))?;
-            
-            Ok(vk.verify(message, &sig).is_ok())
+
+            Ok(vk.verify_with_context(message, context, &sig).is_ok())
         }
         MlDsaLevel::Dsa87 => {
             let vk_bytes: [u8; 2592] = public_key.try_into()
@@ -126,7 +158,7 @@ pub fn verify(
                     public_key.len()
                 )))?;
             let vk = VerifyingKey::<MlDsa87>::decode(&vk_bytes.into());
-            
+
             let sig_bytes: [u8; 4627] = signature.as_bytes().try_into()
                 .map_err(|_| Error::InvalidSignature(format!(
                     "expected {} bytes, got {}",
@@ -135,8 +167,8 @@ pub fn verify(
                 )))?;
             let sig = Signature::<MlDsa87>::decode(&sig_bytes.into())
                 .ok_or_else(|| Error::InvalidSignature("failed to decode signature".to_string()))?;
-            
-            Ok(vk.verify(message, &sig).is_ok())
+
+            Ok(vk.verify_with_context(message, context, &sig).is_ok())
         }
     }
 }
@@ -211,10 +243,34 @@ mod tests {
     #[test]
     fn test_deterministic_keygen() {
         let seed = [42u8; 32];
-        
+
         let kp1 = generate_keypair(MlDsaLevel::Dsa44, &seed).unwrap();
         let kp2 = generate_keypair(MlDsaLevel::Dsa44, &seed).unwrap();
-        
+
         assert_eq!(kp1.public_key(), kp2.public_key());
     }
+
+    #[test]
+    fn test_sign_verify_with_context_roundtrip() {
+        let seed = [42u8; 32];
+        let keypair = generate_keypair(MlDsaLevel::Dsa44, &seed).unwrap();
+
+        let message = b"transfer 10 SIL";
+        let context = b"tx:transfer";
+        let signature = sign_with_context(&keypair, message, context).unwrap();
+
+        assert_eq!(signature.context_len(), context.len());
+        assert!(verify_with_context(keypair.public_key(), keypair.level(), message, context, &signature).unwrap());
+    }
+
+    #[test]
+    fn test_verify_with_context_fails_on_wrong_context() {
+        let seed = [42u8; 32];
+        let keypair = generate_keypair(MlDsaLevel::Dsa44, &seed).unwrap();
+
+        let message = b"transfer 10 SIL";
+        let signature = sign_with_context(&keypair, message, b"tx:transfer").unwrap();
+
+        assert!(!verify_with_context(keypair.public_key(), keypair.level(), message, b"tx:mint", &signature).unwrap());
+    }
 }
@@ -1,16 +1,12 @@
 //! Core derivation functions for BIP39 → ML-DSA
 //!
-//! Uses SHAKE256 to deterministically derive ML-DSA seeds from BIP39 seeds.
+//! Builds the standard BIP44-style path for a level/coin/account/index and
+//! delegates to [`crate::derive_keypair_at_path`], which walks the
+//! hierarchical [`crate::extended::ExtendedMlDsaKey`] tree to its leaf.
 
-use sha3::{
-    Shake256,
-    digest::{ExtendableOutput, Update, XofReader},
-};
+use zeroize::Zeroizing;
 
-use crate::{Error, MlDsaKeyPair, MlDsaLevel, Result, SILICA_COIN_TYPE};
-
-#[cfg(feature = "rustcrypto")]
-use crate::backend_rustcrypto;
+use crate::{DerivationPath, Error, MlDsaKeyPair, MlDsaLevel, Result, SILICA_COIN_TYPE};
 
 /// Convert a BIP39 mnemonic phrase to a 64-byte seed
 ///
@@ -19,7 +15,7 @@ use crate::backend_rustcrypto;
 /// * `passphrase` - Optional passphrase (empty string for none)
 ///
 /// # Returns
-/// 64-byte BIP39 seed
+/// 64-byte BIP39 seed, scrubbed from memory on drop
 ///
 /// # Example
 /// ```
@@ -33,13 +29,58 @@ use crate::backend_rustcrypto;
 /// assert_eq!(seed.len(), 64);
 /// # Ok::<(), ml_dsa_bip39::Error>(())
 /// ```
-pub fn mnemonic_to_seed(mnemonic: &str, passphrase: &str) -> Result<[u8; 64]> {
+pub fn mnemonic_to_seed(mnemonic: &str, passphrase: &str) -> Result<Zeroizing<[u8; 64]>> {
     let mnemonic = bip39::Mnemonic::parse_normalized(mnemonic)
         .map_err(|e| Error::InvalidMnemonic(e.to_string()))?;
-    
+
     let seed = mnemonic.to_seed(passphrase);
-    
-    Ok(seed)
+
+    Ok(Zeroizing::new(seed))
+}
+
+/// Generate a fresh BIP39 mnemonic phrase from a CSPRNG
+///
+/// `word_count` must be 12, 15, 18, 21, or 24, corresponding to 128-256
+/// bits of entropy. Use [`mnemonic_to_seed`] on the result to derive keys,
+/// or [`mnemonic_to_entropy`] to get the compact entropy form for backup.
+///
+/// # Example
+/// ```
+/// use ml_dsa_bip39::generate_mnemonic;
+///
+/// let mnemonic = generate_mnemonic(24)?;
+/// assert_eq!(mnemonic.split_whitespace().count(), 24);
+/// # Ok::<(), ml_dsa_bip39::Error>(())
+/// ```
+pub fn generate_mnemonic(word_count: usize) -> Result<String> {
+    let mnemonic =
+        bip39::Mnemonic::generate(word_count).map_err(|e| Error::InvalidMnemonic(e.to_string()))?;
+
+    Ok(mnemonic.to_string())
+}
+
+/// Extract the raw entropy backing a BIP39 mnemonic phrase
+///
+/// Unlike [`mnemonic_to_seed`], this is not PBKDF2-stretched: it's the
+/// compact source material the mnemonic's checksummed wordlist encodes,
+/// useful for a smaller backup or for deterministically deriving child
+/// mnemonics at a higher layer.
+pub fn mnemonic_to_entropy(mnemonic: &str) -> Result<Vec<u8>> {
+    let mnemonic = bip39::Mnemonic::parse_normalized(mnemonic)
+        .map_err(|e| Error::InvalidMnemonic(e.to_string()))?;
+
+    Ok(mnemonic.to_entropy())
+}
+
+/// Reconstruct a BIP39 mnemonic phrase from raw entropy
+///
+/// `entropy` must be 16, 20, 24, 28, or 32 bytes (128-256 bits), matching
+/// one of the 12/15/18/21/24-word mnemonic lengths.
+pub fn entropy_to_mnemonic(entropy: &[u8]) -> Result<String> {
+    let mnemonic = bip39::Mnemonic::from_entropy(entropy)
+        .map_err(|e| Error::InvalidMnemonic(e.to_string()))?;
+
+    Ok(mnemonic.to_string())
 }
 
 /// Derive an ML-DSA keypair from a BIP39 seed
@@ -53,9 +94,10 @@ pub fn mnemonic_to_seed(mnemonic: &str, passphrase: &str) -> Result<[u8; 64]> {
 /// * `level` - ML-DSA security level
 ///
 /// # Derivation Path
-/// `m/{purpose}'/{coin}'/{account}'/0/{index}`
+/// `m/{purpose}'/{coin}'/{account}'/0'/{index}'`
 ///
-/// Where purpose is level-specific: 8844, 8865, or 8887
+/// Where purpose is level-specific: 8844, 8865, or 8887. Every level is
+/// hardened, since ML-DSA has no public-key derivation.
 ///
 /// # Example
 /// ```
@@ -109,52 +151,8 @@ pub fn derive_keypair_with_coin(
     index: u32,
     level: MlDsaLevel,
 ) -> Result<MlDsaKeyPair> {
-    // Construct derivation path string
-    let path = format!("m/{}'/{}'/{}'/0/{}", level.purpose(), coin, account, index);
-    
-    // Derive 32-byte ML-DSA seed using SHAKE256
-    let ml_dsa_seed = derive_ml_dsa_seed(seed, &path, level);
-    
-    // Generate keypair using the configured backend
-    #[cfg(feature = "rustcrypto")]
-    {
-        backend_rustcrypto::generate_keypair(level, &ml_dsa_seed)
-    }
-    
-    #[cfg(not(any(feature = "rustcrypto")))]
-    {
-        Err(Error::UnsupportedLevel(
-            "No ML-DSA backend enabled. Enable 'rustcrypto' feature.".to_string()
-        ))
-    }
-}
-
-/// Derive a 32-byte ML-DSA seed from BIP39 seed using SHAKE256
-///
-/// Domain separation ensures:
-/// - Different levels produce different seeds (via domain separator)
-/// - Different paths produce different seeds (via path string)
-fn derive_ml_dsa_seed(
-    bip39_seed: &[u8; 64],
-    path: &str,
-    level: MlDsaLevel,
-) -> [u8; 32] {
-    let mut hasher = Shake256::default();
-    
-    // Domain separation by level
-    hasher.update(level.domain_separator());
-    
-    // Include the BIP39 seed
-    hasher.update(bip39_seed);
-    
-    // Include the derivation path
-    hasher.update(path.as_bytes());
-    
-    // Extract exactly 32 bytes
-    let mut ml_dsa_seed = [0u8; 32];
-    hasher.finalize_xof().read(&mut ml_dsa_seed);
-    
-    ml_dsa_seed
+    let path = DerivationPath::bip44(level, coin, account, index);
+    crate::derive_keypair_at_path(seed, &path, level)
 }
 
 #[cfg(test)]
@@ -178,6 +176,45 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_generate_mnemonic_word_counts() {
+        for word_count in [12, 15, 18, 21, 24] {
+            let mnemonic = generate_mnemonic(word_count).unwrap();
+            assert_eq!(mnemonic.split_whitespace().count(), word_count);
+        }
+    }
+
+    #[test]
+    fn test_generate_mnemonic_rejects_invalid_word_count() {
+        let result = generate_mnemonic(13);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_generate_mnemonic_is_random() {
+        let a = generate_mnemonic(12).unwrap();
+        let b = generate_mnemonic(12).unwrap();
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_entropy_roundtrip() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon \
+                        abandon abandon abandon abandon abandon about";
+
+        let entropy = mnemonic_to_entropy(mnemonic).unwrap();
+        assert_eq!(entropy.len(), 16);
+
+        let restored = entropy_to_mnemonic(&entropy).unwrap();
+        assert_eq!(restored, mnemonic);
+    }
+
+    #[test]
+    fn test_entropy_to_mnemonic_rejects_bad_length() {
+        let result = entropy_to_mnemonic(&[0u8; 13]);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_passphrase_changes_seed() {
         let mnemonic = "abandon abandon abandon abandon abandon abandon \
@@ -189,35 +226,50 @@ mod tests {
         assert_ne!(seed1, seed2);
     }
 
+    #[cfg(feature = "rustcrypto")]
     #[test]
-    fn test_derive_ml_dsa_seed_determinism() {
+    fn test_derive_keypair_with_coin_actually_derives() {
+        // Regression test: the standard BIP44-style path must actually be
+        // derivable against the hardened-only extended-key tree it walks,
+        // not just parse and format correctly.
         let bip39_seed = [42u8; 64];
-        let path = "m/8844'/1337'/0'/0/0";
-        
-        let seed1 = derive_ml_dsa_seed(&bip39_seed, path, MlDsaLevel::Dsa44);
-        let seed2 = derive_ml_dsa_seed(&bip39_seed, path, MlDsaLevel::Dsa44);
-        
-        assert_eq!(seed1, seed2);
+
+        let keypair =
+            derive_keypair_with_coin(&bip39_seed, 1337, 0, 0, MlDsaLevel::Dsa44).unwrap();
+
+        assert_eq!(keypair.public_key().len(), MlDsaLevel::Dsa44.public_key_size());
     }
 
+    #[cfg(feature = "rustcrypto")]
     #[test]
-    fn test_derive_ml_dsa_seed_different_paths() {
+    fn test_derive_keypair_with_coin_is_deterministic() {
         let bip39_seed = [42u8; 64];
-        
-        let seed1 = derive_ml_dsa_seed(&bip39_seed, "m/8844'/1337'/0'/0/0", MlDsaLevel::Dsa44);
-        let seed2 = derive_ml_dsa_seed(&bip39_seed, "m/8844'/1337'/0'/0/1", MlDsaLevel::Dsa44);
-        
-        assert_ne!(seed1, seed2);
+
+        let kp1 = derive_keypair_with_coin(&bip39_seed, 1337, 0, 0, MlDsaLevel::Dsa44).unwrap();
+        let kp2 = derive_keypair_with_coin(&bip39_seed, 1337, 0, 0, MlDsaLevel::Dsa44).unwrap();
+
+        assert_eq!(kp1.seed(), kp2.seed());
     }
 
+    #[cfg(feature = "rustcrypto")]
     #[test]
-    fn test_derive_ml_dsa_seed_different_levels() {
+    fn test_derive_keypair_with_coin_different_indices_different_keys() {
         let bip39_seed = [42u8; 64];
-        let path = "m/8844'/1337'/0'/0/0";
-        
-        let seed44 = derive_ml_dsa_seed(&bip39_seed, path, MlDsaLevel::Dsa44);
-        let seed65 = derive_ml_dsa_seed(&bip39_seed, path, MlDsaLevel::Dsa65);
-        
-        assert_ne!(seed44, seed65);
+
+        let kp1 = derive_keypair_with_coin(&bip39_seed, 1337, 0, 0, MlDsaLevel::Dsa44).unwrap();
+        let kp2 = derive_keypair_with_coin(&bip39_seed, 1337, 0, 1, MlDsaLevel::Dsa44).unwrap();
+
+        assert_ne!(kp1.seed(), kp2.seed());
+    }
+
+    #[cfg(feature = "rustcrypto")]
+    #[test]
+    fn test_derive_keypair_with_coin_different_levels_different_keys() {
+        let bip39_seed = [42u8; 64];
+
+        let kp44 = derive_keypair_with_coin(&bip39_seed, 1337, 0, 0, MlDsaLevel::Dsa44).unwrap();
+        let kp65 = derive_keypair_with_coin(&bip39_seed, 1337, 0, 0, MlDsaLevel::Dsa65).unwrap();
+
+        assert_ne!(kp44.seed(), kp65.seed());
     }
 }
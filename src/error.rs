@@ -36,4 +36,36 @@ pub enum Error {
     /// Unsupported security level
     #[error("Unsupported ML-DSA level: {0}")]
     UnsupportedLevel(String),
+
+    /// Invalid keypair encoding
+    #[error("Invalid keypair: {0}")]
+    InvalidKeypair(String),
+
+    /// I/O error while reading or writing a keyfile
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// Malformed or level-mismatched derivation path
+    #[error("Invalid derivation path: {0}")]
+    InvalidPath(String),
+
+    /// Invalid Shamir shard, threshold, or share set
+    #[error("Invalid shard: {0}")]
+    InvalidShard(String),
+
+    /// Invalid FIPS 204 application context string
+    #[error("Invalid context: {0}")]
+    InvalidContext(String),
+
+    /// Invalid or corrupt SS58-style address
+    #[error("Invalid address: {0}")]
+    InvalidAddress(String),
+
+    /// Failed to encrypt a seed/keypair backup
+    #[error("Encryption failed: {0}")]
+    EncryptionFailed(String),
+
+    /// Failed to decrypt a seed/keypair backup
+    #[error("Decryption failed: {0}")]
+    DecryptionFailed(String),
 }
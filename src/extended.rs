@@ -0,0 +1,213 @@
+//! Hierarchical extended-key derivation for ML-DSA seeds
+//!
+//! Replaces flat path-string hashing (the whole path concatenated into one
+//! SHAKE256 call) with per-level chained derivation, so an intermediate
+//! node (e.g. an account) can be exported and used to derive its own
+//! children without the master BIP39 seed.
+//!
+//! The master node is `SHAKE256(domain_separator(level) || bip39_seed)`
+//! read as 64 bytes and split into `key || chain_code`. Each hardened
+//! child at index `i` is `SHAKE256(parent.chain_code || parent.key ||
+//! i.to_be_bytes())`, again read as 64 bytes and split the same way.
+//!
+//! ML-DSA has no public-key derivation (unlike ECC-based BIP32), so every
+//! level here must be hardened - [`ExtendedMlDsaKey::derive_child`] rejects
+//! non-hardened indices rather than silently deriving from public data
+//! that doesn't exist.
+
+use sha3::{
+    Shake256,
+    digest::{ExtendableOutput, Update, XofReader},
+};
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
+
+use crate::{ChildNumber, DerivationPath, Error, MlDsaLevel, Result};
+
+/// A node in the ML-DSA extended-key tree
+///
+/// `key` becomes the ML-DSA seed fed to the backend once derivation
+/// reaches a leaf; `chain_code` is additional entropy mixed into child
+/// derivation so that a leaked child key doesn't reveal its siblings or
+/// parent.
+///
+/// `key` and `chain_code` are scrubbed from memory when a node is dropped.
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+pub struct ExtendedMlDsaKey {
+    key: [u8; 32],
+    chain_code: [u8; 32],
+    #[zeroize(skip)]
+    depth: u8,
+}
+
+impl ExtendedMlDsaKey {
+    /// Derive the master node for `level` from a 64-byte BIP39 seed
+    pub fn master(bip39_seed: &[u8; 64], level: MlDsaLevel) -> Self {
+        let mut hasher = Shake256::default();
+        hasher.update(level.domain_separator());
+        hasher.update(bip39_seed);
+        let (key, chain_code) = split_output(hasher);
+        Self {
+            key,
+            chain_code,
+            depth: 0,
+        }
+    }
+
+    /// Derive the hardened child at `child`
+    ///
+    /// Returns [`Error::InvalidPath`] if `child` is not hardened.
+    pub fn derive_child(&self, child: ChildNumber) -> Result<Self> {
+        if !child.hardened {
+            return Err(Error::InvalidPath(format!(
+                "ML-DSA has no public-key derivation; index {} must be hardened",
+                child.index
+            )));
+        }
+
+        let mut hasher = Shake256::default();
+        hasher.update(&self.chain_code);
+        hasher.update(&self.key);
+        hasher.update(&child.index.to_be_bytes());
+        let (key, chain_code) = split_output(hasher);
+
+        Ok(Self {
+            key,
+            chain_code,
+            depth: self.depth + 1,
+        })
+    }
+
+    /// Walk every component of `path` from this node, one hardened child derivation per level
+    pub fn derive_path(&self, path: &DerivationPath) -> Result<Self> {
+        path.components()
+            .iter()
+            .try_fold(self.clone(), |node, child| node.derive_child(*child))
+    }
+
+    /// The 32-byte key material at this node - the ML-DSA seed at a leaf
+    pub fn key(&self) -> &[u8; 32] {
+        &self.key
+    }
+
+    /// The 32-byte chain code mixed into this node's children
+    pub fn chain_code(&self) -> &[u8; 32] {
+        &self.chain_code
+    }
+
+    /// How many hardened derivations separate this node from the master
+    pub fn depth(&self) -> u8 {
+        self.depth
+    }
+}
+
+impl std::fmt::Debug for ExtendedMlDsaKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ExtendedMlDsaKey")
+            .field("depth", &self.depth)
+            .field("key", &"[REDACTED]")
+            .field("chain_code", &"[REDACTED]")
+            .finish()
+    }
+}
+
+fn split_output(hasher: Shake256) -> ([u8; 32], [u8; 32]) {
+    let mut output = Zeroizing::new([0u8; 64]);
+    hasher.finalize_xof().read(&mut *output);
+    let mut key = [0u8; 32];
+    let mut chain_code = [0u8; 32];
+    key.copy_from_slice(&output[..32]);
+    chain_code.copy_from_slice(&output[32..]);
+    (key, chain_code)
+}
+
+/// Derive the leaf ML-DSA seed for `path` from a 64-byte BIP39 seed
+pub(crate) fn derive_ml_dsa_seed(
+    bip39_seed: &[u8; 64],
+    path: &DerivationPath,
+    level: MlDsaLevel,
+) -> Result<Zeroizing<[u8; 32]>> {
+    let leaf = ExtendedMlDsaKey::master(bip39_seed, level).derive_path(path)?;
+    Ok(Zeroizing::new(*leaf.key()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_master_is_deterministic() {
+        let seed = [1u8; 64];
+        let a = ExtendedMlDsaKey::master(&seed, MlDsaLevel::Dsa44);
+        let b = ExtendedMlDsaKey::master(&seed, MlDsaLevel::Dsa44);
+        assert_eq!(a.key(), b.key());
+        assert_eq!(a.chain_code(), b.chain_code());
+    }
+
+    #[test]
+    fn test_different_levels_different_masters() {
+        let seed = [1u8; 64];
+        let dsa44 = ExtendedMlDsaKey::master(&seed, MlDsaLevel::Dsa44);
+        let dsa65 = ExtendedMlDsaKey::master(&seed, MlDsaLevel::Dsa65);
+        assert_ne!(dsa44.key(), dsa65.key());
+    }
+
+    #[test]
+    fn test_derive_child_rejects_non_hardened() {
+        let seed = [1u8; 64];
+        let master = ExtendedMlDsaKey::master(&seed, MlDsaLevel::Dsa44);
+        let result = master.derive_child(ChildNumber::normal(0));
+        assert!(matches!(result, Err(Error::InvalidPath(_))));
+    }
+
+    #[test]
+    fn test_derive_child_different_indices_different_keys() {
+        let seed = [1u8; 64];
+        let master = ExtendedMlDsaKey::master(&seed, MlDsaLevel::Dsa44);
+        let child0 = master.derive_child(ChildNumber::hardened(0)).unwrap();
+        let child1 = master.derive_child(ChildNumber::hardened(1)).unwrap();
+        assert_ne!(child0.key(), child1.key());
+    }
+
+    #[test]
+    fn test_derive_path_matches_manual_chaining() {
+        let seed = [1u8; 64];
+        let path: DerivationPath = "m/8844'/1337'/0'/0'/7'".parse().unwrap();
+
+        let via_path = ExtendedMlDsaKey::master(&seed, MlDsaLevel::Dsa44)
+            .derive_path(&path)
+            .unwrap();
+
+        let mut manual = ExtendedMlDsaKey::master(&seed, MlDsaLevel::Dsa44);
+        for component in path.components() {
+            manual = manual.derive_child(*component).unwrap();
+        }
+
+        assert_eq!(via_path.key(), manual.key());
+        assert_eq!(via_path.depth(), 5);
+    }
+
+    #[test]
+    fn test_intermediate_node_derives_children_without_master_seed() {
+        let seed = [1u8; 64];
+        let master = ExtendedMlDsaKey::master(&seed, MlDsaLevel::Dsa44);
+        let account = master
+            .derive_child(ChildNumber::hardened(8844))
+            .unwrap()
+            .derive_child(ChildNumber::hardened(1337))
+            .unwrap()
+            .derive_child(ChildNumber::hardened(0))
+            .unwrap();
+
+        // An account-level node alone can derive its own children.
+        let via_account = account
+            .derive_child(ChildNumber::hardened(0))
+            .unwrap()
+            .derive_child(ChildNumber::hardened(7))
+            .unwrap();
+
+        let path: DerivationPath = "m/8844'/1337'/0'/0'/7'".parse().unwrap();
+        let via_master = master.derive_path(&path).unwrap();
+
+        assert_eq!(via_account.key(), via_master.key());
+    }
+}
@@ -0,0 +1,258 @@
+//! Vanity keypair grinding by public-key/address prefix
+//!
+//! Derivation is deterministic and - unlike signing - cheap, so searching
+//! for a keypair whose public key (or a chosen address encoding) satisfies
+//! a caller-supplied predicate (e.g. a case-insensitive prefix match) is
+//! practical by brute force over the derivation `index`. [`grind_keypairs`]
+//! spreads that search across a fixed-size thread pool and collects up to
+//! `count` matches, sorted by index; [`grind_keypair`] is the common
+//! first-match special case. Workers stop as soon as enough matches are
+//! collected, so a match at a lower index that a slower worker is still
+//! evaluating can be missed in favor of one a faster worker already
+//! reported - the result is the lowest index among whatever was found
+//! before the search stopped, not a guarantee that no smaller matching
+//! index exists anywhere in the unsearched remainder.
+//!
+//! This relies on [`crate::derive_keypair_with_coin`] actually succeeding
+//! for ordinary indices - if every derivation errored, the `Ok(keypair)`
+//! arm below would never run and the search would spin to `u32::MAX`
+//! without ever finding (or being able to find) a match.
+
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use crate::{MlDsaKeyPair, MlDsaLevel, Result, derive_keypair_with_coin};
+
+/// What to render a derived keypair's public material as before matching
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GrindTarget {
+    /// Match against the base58-encoded public key
+    PublicKeyBase58,
+    /// Match against the SS58-style address for the given network prefix
+    Address(u8),
+}
+
+impl GrindTarget {
+    fn render(&self, keypair: &MlDsaKeyPair) -> String {
+        match self {
+            GrindTarget::PublicKeyBase58 => bs58::encode(keypair.public_key()).into_string(),
+            GrindTarget::Address(prefix) => keypair.address(*prefix),
+        }
+    }
+}
+
+/// A keypair found by [`grind_keypair`] or [`grind_keypairs`]
+pub struct GrindHit {
+    /// The `index` (within `coin`/`account`) that produced the match
+    pub index: u32,
+    /// The matching keypair
+    pub keypair: MlDsaKeyPair,
+    /// The rendered public key / address string that satisfied the matcher
+    pub rendered: String,
+}
+
+/// The result of a [`grind_keypairs`] search
+pub struct GrindOutcome {
+    /// Matches found, in ascending index order, up to the requested count -
+    /// not necessarily the `count` lowest matching indices in the full
+    /// search space (see [`grind_keypairs`])
+    pub hits: Vec<GrindHit>,
+    /// Total number of indices tried across all worker threads
+    pub attempts: u64,
+}
+
+/// Search for a derivation index whose rendering satisfies `matches`
+///
+/// A thin wrapper around [`grind_keypairs`] with `count` fixed to 1. Returns
+/// `None` if the search is cancelled or the index space is exhausted before
+/// a match is found. Not necessarily the *lowest* matching index overall -
+/// see the module docs for why a concurrent search can settle for a match
+/// above one a slower worker hadn't finished evaluating yet.
+pub fn grind_keypair(
+    seed: &[u8; 64],
+    coin: u32,
+    account: u32,
+    level: MlDsaLevel,
+    target: GrindTarget,
+    matches: impl Fn(&str) -> bool + Sync,
+    thread_count: usize,
+    cancel: Option<&AtomicBool>,
+) -> Option<GrindHit> {
+    grind_keypairs(seed, coin, account, level, target, matches, 1, thread_count, cancel)
+        .hits
+        .into_iter()
+        .next()
+}
+
+/// Search for up to `count` derivation indices whose rendering satisfies `matches`
+///
+/// Walks `index` from 0 across `thread_count.max(1)` worker threads (a
+/// reasonable default is `std::thread::available_parallelism()`), stopping
+/// once `count` matches are found, the index space (`u32::MAX`) is
+/// exhausted, or `cancel` (if given) is observed set. The returned matches
+/// are sorted by index, but that only orders *what was found* - see the
+/// module docs for why this isn't a guarantee that `count` is the smallest
+/// possible set of matching indices.
+pub fn grind_keypairs(
+    seed: &[u8; 64],
+    coin: u32,
+    account: u32,
+    level: MlDsaLevel,
+    target: GrindTarget,
+    matches: impl Fn(&str) -> bool + Sync,
+    count: usize,
+    thread_count: usize,
+    cancel: Option<&AtomicBool>,
+) -> GrindOutcome {
+    if count == 0 {
+        return GrindOutcome {
+            hits: Vec::new(),
+            attempts: 0,
+        };
+    }
+
+    let thread_count = thread_count.max(1);
+    let next_index = AtomicU32::new(0);
+    let attempts = AtomicU64::new(0);
+    let stop = AtomicBool::new(false);
+    let found = Mutex::new(Vec::<GrindHit>::new());
+
+    std::thread::scope(|scope| {
+        for _ in 0..thread_count {
+            scope.spawn(|| {
+                loop {
+                    if stop.load(Ordering::Relaxed)
+                        || cancel.is_some_and(|c| c.load(Ordering::Relaxed))
+                    {
+                        stop.store(true, Ordering::Relaxed);
+                        return;
+                    }
+
+                    let index = next_index.fetch_add(1, Ordering::Relaxed);
+                    if index == u32::MAX {
+                        stop.store(true, Ordering::Relaxed);
+                        return;
+                    }
+                    attempts.fetch_add(1, Ordering::Relaxed);
+
+                    let Ok(keypair) = derive_keypair_with_coin(seed, coin, account, index, level)
+                    else {
+                        continue;
+                    };
+                    let rendered = target.render(&keypair);
+                    if !matches(&rendered) {
+                        continue;
+                    }
+
+                    let mut found = found.lock().unwrap();
+                    found.push(GrindHit {
+                        index,
+                        keypair,
+                        rendered,
+                    });
+                    if found.len() >= count {
+                        stop.store(true, Ordering::Relaxed);
+                        return;
+                    }
+                }
+            });
+        }
+    });
+
+    let mut hits = found.into_inner().unwrap();
+    hits.sort_by_key(|hit| hit.index);
+    hits.truncate(count);
+
+    GrindOutcome {
+        hits,
+        attempts: attempts.load(Ordering::Relaxed),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grind_keypair_finds_matching_prefix() {
+        let seed = [1u8; 64];
+        let hit = grind_keypair(
+            &seed,
+            1337,
+            0,
+            MlDsaLevel::Dsa44,
+            GrindTarget::PublicKeyBase58,
+            |rendered| rendered.starts_with('1') || rendered.starts_with('2'),
+            2,
+            None,
+        );
+
+        let hit = hit.expect("a single-character base58 prefix should be found quickly");
+        assert!(hit.rendered.starts_with('1') || hit.rendered.starts_with('2'));
+
+        let verify = derive_keypair_with_coin(&seed, 1337, 0, hit.index, MlDsaLevel::Dsa44)
+            .unwrap();
+        assert_eq!(verify.public_key(), hit.keypair.public_key());
+    }
+
+    #[test]
+    fn test_grind_keypairs_returns_sorted_by_index() {
+        let seed = [2u8; 64];
+        let outcome = grind_keypairs(
+            &seed,
+            1337,
+            0,
+            MlDsaLevel::Dsa44,
+            GrindTarget::PublicKeyBase58,
+            |_| true,
+            5,
+            2,
+            None,
+        );
+
+        assert_eq!(outcome.hits.len(), 5);
+        let indices: Vec<u32> = outcome.hits.iter().map(|hit| hit.index).collect();
+        let mut sorted = indices.clone();
+        sorted.sort_unstable();
+        assert_eq!(indices, sorted);
+        assert!(outcome.attempts >= 5);
+    }
+
+    #[test]
+    fn test_grind_keypairs_respects_cancellation() {
+        let seed = [3u8; 64];
+        let cancel = AtomicBool::new(true);
+        let outcome = grind_keypairs(
+            &seed,
+            1337,
+            0,
+            MlDsaLevel::Dsa44,
+            GrindTarget::PublicKeyBase58,
+            |rendered| rendered.starts_with("impossible-prefix-zzz"),
+            1,
+            2,
+            Some(&cancel),
+        );
+
+        assert!(outcome.hits.is_empty());
+    }
+
+    #[test]
+    fn test_grind_keypairs_zero_count_is_a_no_op() {
+        let seed = [4u8; 64];
+        let outcome = grind_keypairs(
+            &seed,
+            1337,
+            0,
+            MlDsaLevel::Dsa44,
+            GrindTarget::PublicKeyBase58,
+            |_| true,
+            0,
+            2,
+            None,
+        );
+
+        assert!(outcome.hits.is_empty());
+        assert_eq!(outcome.attempts, 0);
+    }
+}
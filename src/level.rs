@@ -112,6 +112,25 @@ impl MlDsaLevel {
             Self::Dsa87 => "ML-DSA-87",
         }
     }
+
+    /// Encode this level as a single byte tag for compact serialization
+    pub fn to_tag(self) -> u8 {
+        match self {
+            Self::Dsa44 => 0,
+            Self::Dsa65 => 1,
+            Self::Dsa87 => 2,
+        }
+    }
+
+    /// Decode a level from the byte tag produced by [`MlDsaLevel::to_tag`]
+    pub fn from_tag(tag: u8) -> Option<Self> {
+        match tag {
+            0 => Some(Self::Dsa44),
+            1 => Some(Self::Dsa65),
+            2 => Some(Self::Dsa87),
+            _ => None,
+        }
+    }
 }
 
 impl std::fmt::Display for MlDsaLevel {
@@ -161,4 +180,12 @@ mod tests {
         assert_eq!(MlDsaLevel::Dsa65.public_key_size(), 1952);
         assert_eq!(MlDsaLevel::Dsa87.public_key_size(), 2592);
     }
+
+    #[test]
+    fn test_tag_roundtrip() {
+        for level in [MlDsaLevel::Dsa44, MlDsaLevel::Dsa65, MlDsaLevel::Dsa87] {
+            assert_eq!(MlDsaLevel::from_tag(level.to_tag()), Some(level));
+        }
+        assert_eq!(MlDsaLevel::from_tag(0xff), None);
+    }
 }
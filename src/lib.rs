@@ -45,25 +45,52 @@
 //!
 //! Each security level uses a unique purpose field to prevent collisions:
 //!
-//! - ML-DSA-44: `m/8844'/coin'/account'/0/index`
-//! - ML-DSA-65: `m/8865'/coin'/account'/0/index`
-//! - ML-DSA-87: `m/8887'/coin'/account'/0/index`
+//! - ML-DSA-44: `m/8844'/coin'/account'/0'/index'`
+//! - ML-DSA-65: `m/8865'/coin'/account'/0'/index'`
+//! - ML-DSA-87: `m/8887'/coin'/account'/0'/index'`
+//!
+//! Every level is hardened: ML-DSA has no public-key derivation, so a
+//! non-hardened level (discoverable from public data alone, as in ECC-based
+//! BIP32) has no equivalent here.
 
 #![deny(unsafe_code)]
 #![warn(missing_docs, rust_2018_idioms)]
 
+mod address;
+mod backend;
+mod backup;
 mod derivation;
 mod error;
+mod extended;
 mod level;
+mod path;
+mod shard;
 mod types;
 
 #[cfg(feature = "rustcrypto")]
 mod backend_rustcrypto;
+#[cfg(feature = "rustcrypto")]
+mod grind;
 
-pub use derivation::{derive_keypair, derive_keypair_with_coin, mnemonic_to_seed};
+pub use address::decode_address;
+pub use backend::Backend;
+#[cfg(feature = "rustcrypto")]
+pub use backend::RustCryptoBackend;
+pub use backup::{decrypt_seed, encrypt_keypair, encrypt_seed};
+#[cfg(feature = "rustcrypto")]
+pub use backup::decrypt_keypair;
+pub use derivation::{
+    derive_keypair, derive_keypair_with_coin, entropy_to_mnemonic, generate_mnemonic,
+    mnemonic_to_entropy, mnemonic_to_seed,
+};
 pub use error::Error;
+pub use extended::ExtendedMlDsaKey;
+#[cfg(feature = "rustcrypto")]
+pub use grind::{GrindHit, GrindOutcome, GrindTarget, grind_keypair, grind_keypairs};
 pub use level::MlDsaLevel;
-pub use types::{MlDsaKeyPair, MlDsaSignature};
+pub use path::{ChildNumber, DerivationPath, derive_keypair_at_path};
+pub use shard::{Shard, recover_bip39_seed, recover_seed, split_bip39_seed, split_seed};
+pub use types::{MAX_CONTEXT_LEN, MlDsaKeyPair, MlDsaSignature};
 
 /// Result type for ml-dsa-bip39 operations
 pub type Result<T> = std::result::Result<T, Error>;
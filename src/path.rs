@@ -0,0 +1,249 @@
+//! A parseable BIP44-style derivation path
+//!
+//! Replaces the ad-hoc `format!("m/{}'/{}'/{}'/0/{}", ...)` string building
+//! with a structured type that can be parsed from and rendered back to the
+//! `m/8844'/coin'/account'/0'/index'` syntax, and validated against a
+//! [`MlDsaLevel`]'s purpose field before it is ever hashed.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{Error, MlDsaKeyPair, MlDsaLevel, Result};
+
+#[cfg(feature = "rustcrypto")]
+use crate::backend_rustcrypto;
+use crate::extended;
+
+/// A single level of a derivation path, optionally hardened (`'` suffix)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChildNumber {
+    /// The unhardened index
+    pub index: u32,
+    /// Whether this level is hardened
+    pub hardened: bool,
+}
+
+impl ChildNumber {
+    /// Build a hardened child number
+    pub fn hardened(index: u32) -> Self {
+        Self {
+            index,
+            hardened: true,
+        }
+    }
+
+    /// Build a non-hardened child number
+    pub fn normal(index: u32) -> Self {
+        Self {
+            index,
+            hardened: false,
+        }
+    }
+}
+
+impl fmt::Display for ChildNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.hardened {
+            write!(f, "{}'", self.index)
+        } else {
+            write!(f, "{}", self.index)
+        }
+    }
+}
+
+impl FromStr for ChildNumber {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (digits, hardened) = match s.strip_suffix('\'') {
+            Some(stripped) => (stripped, true),
+            None => (s, false),
+        };
+        let index = digits
+            .parse::<u32>()
+            .map_err(|_| Error::InvalidPath(format!("invalid path component: '{s}'")))?;
+        Ok(Self { index, hardened })
+    }
+}
+
+/// A parsed derivation path, e.g. `m/8844'/1337'/0'/0'/7'`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DerivationPath {
+    components: Vec<ChildNumber>,
+}
+
+impl DerivationPath {
+    /// Build the crate's standard 5-level path:
+    /// `m/{purpose}'/{coin}'/{account}'/0'/{index}'`
+    ///
+    /// All five levels are hardened. ML-DSA has no public-key derivation
+    /// (unlike ECC-based BIP32), so a non-hardened level would be
+    /// undiscoverable from the master seed but would still reject in
+    /// [`crate::ExtendedMlDsaKey::derive_child`] - the `'` suffix is kept on
+    /// every component (including the BIP44 "change" level and the leaf
+    /// index) to reflect that rather than silently diverging from it.
+    pub fn bip44(level: MlDsaLevel, coin: u32, account: u32, index: u32) -> Self {
+        Self {
+            components: vec![
+                ChildNumber::hardened(level.purpose()),
+                ChildNumber::hardened(coin),
+                ChildNumber::hardened(account),
+                ChildNumber::hardened(0),
+                ChildNumber::hardened(index),
+            ],
+        }
+    }
+
+    /// The path's levels, in order, not including the leading `m`
+    pub fn components(&self) -> &[ChildNumber] {
+        &self.components
+    }
+
+    /// Check that the path's purpose field (first component) matches `level`
+    fn validate_for_level(&self, level: MlDsaLevel) -> Result<()> {
+        match self.components.first() {
+            Some(purpose) if purpose.hardened && purpose.index == level.purpose() => Ok(()),
+            Some(purpose) => Err(Error::InvalidPath(format!(
+                "path purpose {purpose} does not match {level} (expected {}')",
+                level.purpose()
+            ))),
+            None => Err(Error::InvalidPath("path has no components".to_string())),
+        }
+    }
+}
+
+impl FromStr for DerivationPath {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut segments = s.split('/');
+        match segments.next() {
+            Some("m") => {}
+            _ => return Err(Error::InvalidPath(format!("path must start with 'm': {s}"))),
+        }
+
+        let components = segments
+            .map(ChildNumber::from_str)
+            .collect::<Result<Vec<_>>>()?;
+        if components.is_empty() {
+            return Err(Error::InvalidPath(format!("path has no components: {s}")));
+        }
+
+        Ok(Self { components })
+    }
+}
+
+impl fmt::Display for DerivationPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "m")?;
+        for component in &self.components {
+            write!(f, "/{component}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Derive an ML-DSA keypair from an explicit, arbitrary-depth [`DerivationPath`]
+///
+/// Rejects paths whose purpose field doesn't match `level` with
+/// [`Error::InvalidPath`] before any hashing takes place. This is the
+/// canonical derivation entry point: [`crate::derive_keypair_with_coin`]
+/// builds the standard 5-level BIP44-style path and delegates here rather
+/// than duplicating the walk.
+pub fn derive_keypair_at_path(
+    seed: &[u8; 64],
+    path: &DerivationPath,
+    level: MlDsaLevel,
+) -> Result<MlDsaKeyPair> {
+    path.validate_for_level(level)?;
+
+    let ml_dsa_seed = extended::derive_ml_dsa_seed(seed, path, level)?;
+
+    #[cfg(feature = "rustcrypto")]
+    {
+        backend_rustcrypto::generate_keypair(level, &ml_dsa_seed)
+    }
+
+    #[cfg(not(feature = "rustcrypto"))]
+    {
+        Err(Error::UnsupportedLevel(
+            "No ML-DSA backend enabled. Enable 'rustcrypto' feature.".to_string(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip_display_parse() {
+        let path = DerivationPath::bip44(MlDsaLevel::Dsa44, 1337, 0, 7);
+        let rendered = path.to_string();
+        assert_eq!(rendered, "m/8844'/1337'/0'/0'/7'");
+
+        let parsed: DerivationPath = rendered.parse().unwrap();
+        assert_eq!(parsed, path);
+    }
+
+    #[test]
+    fn test_parse_rejects_missing_m() {
+        let result = DerivationPath::from_str("8844'/1337'/0'/0/7");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_rejects_non_numeric_component() {
+        let result = DerivationPath::from_str("m/abc'/1337'/0'/0/7");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_child_number_hardened_suffix() {
+        let hardened: ChildNumber = "44'".parse().unwrap();
+        assert_eq!(hardened, ChildNumber::hardened(44));
+
+        let normal: ChildNumber = "44".parse().unwrap();
+        assert_eq!(normal, ChildNumber::normal(44));
+    }
+
+    #[test]
+    fn test_validate_for_level_rejects_mismatched_purpose() {
+        let path = DerivationPath::bip44(MlDsaLevel::Dsa65, 1337, 0, 0);
+        let result = path.validate_for_level(MlDsaLevel::Dsa44);
+        assert!(result.is_err());
+    }
+
+    #[cfg(feature = "rustcrypto")]
+    #[test]
+    fn test_derive_keypair_at_path_rejects_level_mismatch() {
+        let bip39_seed = [1u8; 64];
+        let path = DerivationPath::bip44(MlDsaLevel::Dsa65, 1337, 0, 0);
+
+        let result = derive_keypair_at_path(&bip39_seed, &path, MlDsaLevel::Dsa44);
+        assert!(matches!(result, Err(Error::InvalidPath(_))));
+    }
+
+    #[cfg(feature = "rustcrypto")]
+    #[test]
+    fn test_derive_keypair_at_path_matches_derive_keypair_with_coin() {
+        let bip39_seed = [1u8; 64];
+        let path = DerivationPath::bip44(MlDsaLevel::Dsa44, 1337, 0, 3);
+
+        let via_path = derive_keypair_at_path(&bip39_seed, &path, MlDsaLevel::Dsa44).unwrap();
+        let via_coin =
+            crate::derive_keypair_with_coin(&bip39_seed, 1337, 0, 3, MlDsaLevel::Dsa44).unwrap();
+
+        assert_eq!(via_path.seed(), via_coin.seed());
+    }
+
+    #[cfg(feature = "rustcrypto")]
+    #[test]
+    fn test_derive_keypair_at_path_supports_arbitrary_depth() {
+        let bip39_seed = [1u8; 64];
+        let path: DerivationPath = "m/8844'/1337'/0'/0'/7'".parse().unwrap();
+
+        let keypair = derive_keypair_at_path(&bip39_seed, &path, MlDsaLevel::Dsa44).unwrap();
+        assert_eq!(keypair.level(), MlDsaLevel::Dsa44);
+    }
+}
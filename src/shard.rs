@@ -0,0 +1,327 @@
+//! SLIP-0039-style Shamir secret sharing of seed material
+//!
+//! Splits the ML-DSA seed (or the BIP39 seed) into `n` shares with a
+//! recovery threshold `t`: for each byte of the secret, a degree-`t-1`
+//! polynomial is chosen whose constant term is that byte and whose other
+//! coefficients are random, then evaluated at distinct nonzero
+//! x-coordinates `1..=n`. Recovery runs Lagrange interpolation at `x = 0`
+//! over GF(256) (the AES field, reduction polynomial `0x11b`).
+
+use std::collections::HashSet;
+use std::sync::OnceLock;
+
+use rand::{RngCore, rngs::OsRng};
+use sha3::{
+    Shake256,
+    digest::{ExtendableOutput, Update, XofReader},
+};
+
+use crate::{Error, Result};
+
+/// AES-field exponential/logarithm tables for GF(256) multiplication
+struct GfTables {
+    exp: [u8; 255],
+    log: [u8; 256],
+}
+
+fn gf_tables() -> &'static GfTables {
+    static TABLES: OnceLock<GfTables> = OnceLock::new();
+    TABLES.get_or_init(|| {
+        let mut exp = [0u8; 255];
+        let mut log = [0u8; 256];
+        // 0x02 is not primitive in this field (order 51, not 255) and would
+        // leave `log` zero for 204 of the 255 nonzero elements. 0x03 is a
+        // true generator, so walk x *= 0x03 (mod 0x11b) instead.
+        let mut x: u16 = 1;
+        for i in 0..255 {
+            exp[i] = x as u8;
+            log[x as usize] = i as u8;
+            let doubled = x << 1;
+            let doubled = if doubled & 0x100 != 0 {
+                doubled ^ 0x11b
+            } else {
+                doubled
+            };
+            x ^= doubled;
+        }
+        GfTables { exp, log }
+    })
+}
+
+fn gf_mul(a: u8, b: u8) -> u8 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+    let t = gf_tables();
+    let sum = t.log[a as usize] as u16 + t.log[b as usize] as u16;
+    t.exp[(sum % 255) as usize]
+}
+
+fn gf_div(a: u8, b: u8) -> u8 {
+    if a == 0 {
+        return 0;
+    }
+    let t = gf_tables();
+    let diff = (t.log[a as usize] as i16 - t.log[b as usize] as i16).rem_euclid(255);
+    t.exp[diff as usize]
+}
+
+fn shard_checksum(index: u8, threshold: u8, bytes: &[u8]) -> u8 {
+    let mut hasher = Shake256::default();
+    hasher.update(&[index, threshold]);
+    hasher.update(bytes);
+    let mut out = [0u8; 1];
+    hasher.finalize_xof().read(&mut out);
+    out[0]
+}
+
+/// One share of a Shamir-split secret
+///
+/// Carries its x-coordinate (`index`), the `threshold` it was split with,
+/// and a checksum over its own index, threshold and bytes, so a corrupted
+/// shard is rejected before it corrupts a recovery attempt. Shards from
+/// different splits are rejected only when their thresholds differ; shards
+/// from different splits that share a threshold are not detected and
+/// recover to a wrong secret.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Shard {
+    index: u8,
+    threshold: u8,
+    bytes: Vec<u8>,
+    checksum: u8,
+}
+
+impl Shard {
+    /// The x-coordinate this shard was evaluated at (nonzero, 1..=shares)
+    pub fn index(&self) -> u8 {
+        self.index
+    }
+
+    /// The recovery threshold this shard was split with
+    pub fn threshold(&self) -> u8 {
+        self.threshold
+    }
+
+    /// The share's byte vector (same length as the original secret)
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    fn verify_checksum(&self) -> Result<()> {
+        if shard_checksum(self.index, self.threshold, &self.bytes) != self.checksum {
+            return Err(Error::InvalidShard(format!(
+                "checksum mismatch on shard {}",
+                self.index
+            )));
+        }
+        Ok(())
+    }
+}
+
+fn split_bytes(secret: &[u8], threshold: u8, shares: u8) -> Result<Vec<Shard>> {
+    if threshold == 0 || shares == 0 {
+        return Err(Error::InvalidShard(
+            "threshold and shares must be nonzero".to_string(),
+        ));
+    }
+    if threshold > shares {
+        return Err(Error::InvalidShard(format!(
+            "threshold {threshold} exceeds share count {shares}"
+        )));
+    }
+
+    // coefficients[0] is the secret byte for each position; the rest are random
+    let mut coefficients: Vec<Vec<u8>> = vec![vec![0u8; secret.len()]; threshold as usize];
+    coefficients[0].copy_from_slice(secret);
+    let mut rng = OsRng;
+    for coefficient in coefficients.iter_mut().skip(1) {
+        rng.fill_bytes(coefficient);
+    }
+
+    let mut shards = Vec::with_capacity(shares as usize);
+    for x in 1..=shares {
+        let mut bytes = vec![0u8; secret.len()];
+        for (j, byte) in bytes.iter_mut().enumerate() {
+            let mut y = 0u8;
+            let mut x_pow = 1u8;
+            for coefficient in &coefficients {
+                y ^= gf_mul(coefficient[j], x_pow);
+                x_pow = gf_mul(x_pow, x);
+            }
+            *byte = y;
+        }
+        let checksum = shard_checksum(x, threshold, &bytes);
+        shards.push(Shard {
+            index: x,
+            threshold,
+            bytes,
+            checksum,
+        });
+    }
+    Ok(shards)
+}
+
+fn recover_bytes(shards: &[Shard], expected_len: usize) -> Result<Vec<u8>> {
+    if shards.is_empty() {
+        return Err(Error::InvalidShard("no shards provided".to_string()));
+    }
+
+    let threshold = shards[0].threshold;
+    if shards.iter().any(|s| s.threshold != threshold) {
+        return Err(Error::InvalidShard(
+            "shards come from different splits (mismatched threshold)".to_string(),
+        ));
+    }
+    if (shards.len() as u8) < threshold {
+        return Err(Error::InvalidShard(format!(
+            "need at least {threshold} shards, got {}",
+            shards.len()
+        )));
+    }
+
+    let mut seen = HashSet::new();
+    for shard in shards {
+        shard.verify_checksum()?;
+        if shard.bytes.len() != expected_len {
+            return Err(Error::InvalidShard(format!(
+                "expected {expected_len}-byte shards, got {}",
+                shard.bytes.len()
+            )));
+        }
+        if !seen.insert(shard.index) {
+            return Err(Error::InvalidShard(format!(
+                "duplicate shard index {}",
+                shard.index
+            )));
+        }
+    }
+
+    let used = &shards[..threshold as usize];
+    let mut secret = vec![0u8; expected_len];
+    for (j, byte) in secret.iter_mut().enumerate() {
+        let mut acc = 0u8;
+        for (i, shard_i) in used.iter().enumerate() {
+            let mut numerator = 1u8;
+            let mut denominator = 1u8;
+            for (k, shard_k) in used.iter().enumerate() {
+                if i == k {
+                    continue;
+                }
+                numerator = gf_mul(numerator, shard_k.index);
+                denominator = gf_mul(denominator, shard_i.index ^ shard_k.index);
+            }
+            acc ^= gf_mul(shard_i.bytes[j], gf_div(numerator, denominator));
+        }
+        *byte = acc;
+    }
+    Ok(secret)
+}
+
+/// Split a 32-byte ML-DSA seed into `shares` shards with recovery threshold `threshold`
+pub fn split_seed(seed: &[u8; 32], threshold: u8, shares: u8) -> Result<Vec<Shard>> {
+    split_bytes(seed, threshold, shares)
+}
+
+/// Recover a 32-byte ML-DSA seed from at least `threshold` of its shards
+pub fn recover_seed(shards: &[Shard]) -> Result<[u8; 32]> {
+    let bytes = recover_bytes(shards, 32)?;
+    let mut seed = [0u8; 32];
+    seed.copy_from_slice(&bytes);
+    Ok(seed)
+}
+
+/// Split a 64-byte BIP39 seed into `shares` shards with recovery threshold `threshold`
+pub fn split_bip39_seed(seed: &[u8; 64], threshold: u8, shares: u8) -> Result<Vec<Shard>> {
+    split_bytes(seed, threshold, shares)
+}
+
+/// Recover a 64-byte BIP39 seed from at least `threshold` of its shards
+pub fn recover_bip39_seed(shards: &[Shard]) -> Result<[u8; 64]> {
+    let bytes = recover_bytes(shards, 64)?;
+    let mut seed = [0u8; 64];
+    seed.copy_from_slice(&bytes);
+    Ok(seed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gf_tables_are_a_bijection_over_nonzero_elements() {
+        let t = gf_tables();
+
+        let mut seen = HashSet::new();
+        for &value in t.exp.iter() {
+            assert_ne!(value, 0, "a true generator never produces zero");
+            assert!(seen.insert(value), "exp[] must cover each nonzero byte exactly once");
+        }
+        assert_eq!(seen.len(), 255);
+
+        for x in 1u16..=255 {
+            assert_eq!(t.exp[t.log[x as usize] as usize], x as u8);
+        }
+    }
+
+    #[test]
+    fn test_split_and_recover_roundtrip() {
+        let seed = [42u8; 32];
+        let shards = split_seed(&seed, 3, 5).unwrap();
+        assert_eq!(shards.len(), 5);
+
+        let recovered = recover_seed(&shards[1..4]).unwrap();
+        assert_eq!(recovered, seed);
+    }
+
+    #[test]
+    fn test_recover_with_any_threshold_subset() {
+        let seed = [7u8; 32];
+        let shards = split_seed(&seed, 2, 4).unwrap();
+
+        let recovered_a = recover_seed(&[shards[0].clone(), shards[1].clone()]).unwrap();
+        let recovered_b = recover_seed(&[shards[2].clone(), shards[3].clone()]).unwrap();
+        assert_eq!(recovered_a, seed);
+        assert_eq!(recovered_b, seed);
+    }
+
+    #[test]
+    fn test_recover_fails_below_threshold() {
+        let seed = [1u8; 32];
+        let shards = split_seed(&seed, 3, 5).unwrap();
+        let result = recover_seed(&shards[0..2]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_recover_rejects_corrupt_shard() {
+        let seed = [1u8; 32];
+        let mut shards = split_seed(&seed, 2, 3).unwrap();
+        shards[0].bytes[0] ^= 0xff;
+
+        let result = recover_seed(&shards[0..2]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_recover_rejects_duplicate_index() {
+        let seed = [1u8; 32];
+        let shards = split_seed(&seed, 2, 3).unwrap();
+        let result = recover_seed(&[shards[0].clone(), shards[0].clone()]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_split_seed_rejects_threshold_above_shares() {
+        let seed = [1u8; 32];
+        let result = split_seed(&seed, 5, 3);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_bip39_seed_roundtrip() {
+        let seed = [9u8; 64];
+        let shards = split_bip39_seed(&seed, 2, 3).unwrap();
+        let recovered = recover_bip39_seed(&shards[0..2]).unwrap();
+        assert_eq!(recovered, seed);
+    }
+}
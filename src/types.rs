@@ -5,7 +5,7 @@
 
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
-use crate::{Error, MlDsaLevel, Result};
+use crate::{Backend, Error, MlDsaLevel, Result};
 
 #[cfg(feature = "rustcrypto")]
 use crate::backend_rustcrypto;
@@ -69,18 +69,168 @@ impl MlDsaKeyPair {
         backend_rustcrypto::verify(&self.public_key, self.level, message, signature)
     }
 
+    /// Sign a message using an explicit [`Backend`] rather than the default
+    pub fn sign_with<B: Backend>(&self, backend: &B, message: &[u8]) -> Result<MlDsaSignature> {
+        backend.sign(self, message)
+    }
+
+    /// Verify a signature using an explicit [`Backend`] rather than the default
+    pub fn verify_with<B: Backend>(
+        &self,
+        backend: &B,
+        message: &[u8],
+        signature: &MlDsaSignature,
+    ) -> Result<bool> {
+        backend.verify(&self.public_key, self.level, message, signature)
+    }
+
+    /// Sign a message bound to an application context string (FIPS 204 ML-DSA.Sign)
+    ///
+    /// The context domain-separates signatures (e.g. distinguishing transaction
+    /// types) and must be at most [`MAX_CONTEXT_LEN`] bytes.
+    #[cfg(feature = "rustcrypto")]
+    pub fn sign_with_context(&self, message: &[u8], context: &[u8]) -> Result<MlDsaSignature> {
+        if context.len() > MAX_CONTEXT_LEN {
+            return Err(Error::InvalidContext(format!(
+                "context must be at most {MAX_CONTEXT_LEN} bytes, got {}",
+                context.len()
+            )));
+        }
+        backend_rustcrypto::sign_with_context(self, message, context)
+    }
+
+    /// Verify a signature produced by [`sign_with_context`](Self::sign_with_context)
+    ///
+    /// Fails with [`Error::InvalidContext`] if `context`'s length doesn't match
+    /// the length the signature was created with.
+    #[cfg(feature = "rustcrypto")]
+    pub fn verify_with_context(
+        &self,
+        message: &[u8],
+        context: &[u8],
+        signature: &MlDsaSignature,
+    ) -> Result<bool> {
+        if context.len() != signature.context_len() {
+            return Err(Error::InvalidContext(format!(
+                "context length {} doesn't match signature's {}",
+                context.len(),
+                signature.context_len()
+            )));
+        }
+        backend_rustcrypto::verify_with_context(
+            &self.public_key,
+            self.level,
+            message,
+            context,
+            signature,
+        )
+    }
+
+    /// Render this keypair's public key as a checksummed, network-tagged address
+    ///
+    /// See [`crate::address::encode_address`] for the encoding.
+    pub fn address(&self, prefix: u8) -> String {
+        crate::address::encode_address(prefix, &self.public_key)
+    }
+
     /// Get the derivation path used to create this keypair
     ///
-    /// Format: `m/{purpose}'/{coin}'/{account}'/0/{index}`
+    /// Format: `m/{purpose}'/{coin}'/{account}'/0'/{index}'`
     pub fn derivation_path(&self, coin: u32, account: u32, index: u32) -> String {
         format!(
-            "m/{}'/{}'/{}'/0/{}",
+            "m/{}'/{}'/{}'/0'/{}'",
             self.level.purpose(),
             coin,
             account,
             index
         )
     }
+
+    /// Encode this keypair as a level tag byte, the 32-byte seed, and the public key
+    ///
+    /// The public key is redundant with the seed (it's regenerated
+    /// deterministically from it), but it's included so
+    /// [`from_bytes`](Self::from_bytes) has something to check a reloaded
+    /// key against - a seed or level byte flipped in storage would
+    /// otherwise silently reload as a different, valid-looking keypair.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(1 + self.seed.len() + self.public_key.len());
+        out.push(self.level.to_tag());
+        out.extend_from_slice(&self.seed);
+        out.extend_from_slice(&self.public_key);
+        out
+    }
+
+    /// Reconstruct a keypair from the bytes produced by [`to_bytes`](Self::to_bytes)
+    ///
+    /// Regenerates the public key from the seed via the configured backend
+    /// and rejects the keyfile with [`Error::InvalidKeypair`] if it doesn't
+    /// match the stored public key, catching corruption that a seed-only
+    /// encoding couldn't detect.
+    #[cfg(feature = "rustcrypto")]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() < 1 + 32 {
+            return Err(Error::InvalidKeypair(format!(
+                "expected at least 33 bytes (1 level tag + 32 byte seed), got {}",
+                bytes.len()
+            )));
+        }
+        let level = MlDsaLevel::from_tag(bytes[0])
+            .ok_or_else(|| Error::InvalidKeypair(format!("unknown level tag {}", bytes[0])))?;
+
+        let expected_len = 1 + 32 + level.public_key_size();
+        if bytes.len() != expected_len {
+            return Err(Error::InvalidKeypair(format!(
+                "expected {expected_len} bytes (1 level tag + 32 byte seed + {}-byte public key) for {level}, got {}",
+                level.public_key_size(),
+                bytes.len()
+            )));
+        }
+
+        let mut seed = [0u8; 32];
+        seed.copy_from_slice(&bytes[1..33]);
+        let stored_public_key = &bytes[33..];
+
+        let regenerated = backend_rustcrypto::generate_keypair(level, &seed)?;
+        if regenerated.public_key() != stored_public_key {
+            return Err(Error::InvalidKeypair(
+                "seed re-derives to a different public key than the one stored; keyfile is corrupt".to_string(),
+            ));
+        }
+        Ok(regenerated)
+    }
+
+    /// Encode this keypair as a base58 string (see [`to_bytes`](Self::to_bytes))
+    pub fn to_base58_string(&self) -> String {
+        bs58::encode(self.to_bytes()).into_string()
+    }
+
+    /// Decode a keypair from the base58 string produced by [`to_base58_string`](Self::to_base58_string)
+    #[cfg(feature = "rustcrypto")]
+    pub fn from_base58_string(s: &str) -> Result<Self> {
+        let bytes = bs58::decode(s)
+            .into_vec()
+            .map_err(|e| Error::InvalidKeypair(format!("invalid base58: {e}")))?;
+        Self::from_bytes(&bytes)
+    }
+
+    /// Write this keypair to `path` as a JSON byte array, Solana-keyfile style
+    pub fn write_to_file<P: AsRef<std::path::Path>>(&self, path: P) -> Result<()> {
+        let bytes = self.to_bytes();
+        let json = serde_json::to_string(&bytes)
+            .map_err(|e| Error::InvalidKeypair(format!("failed to encode keyfile: {e}")))?;
+        std::fs::write(path, json)?;
+        Ok(())
+    }
+
+    /// Read a keypair previously written with [`write_to_file`](Self::write_to_file)
+    #[cfg(feature = "rustcrypto")]
+    pub fn read_from_file<P: AsRef<std::path::Path>>(path: P) -> Result<Self> {
+        let json = std::fs::read_to_string(path)?;
+        let bytes: Vec<u8> = serde_json::from_str(&json)
+            .map_err(|e| Error::InvalidKeypair(format!("failed to decode keyfile: {e}")))?;
+        Self::from_bytes(&bytes)
+    }
 }
 
 impl std::fmt::Debug for MlDsaKeyPair {
@@ -93,6 +243,9 @@ impl std::fmt::Debug for MlDsaKeyPair {
     }
 }
 
+/// Maximum length of a FIPS 204 application context string, in bytes
+pub const MAX_CONTEXT_LEN: usize = 255;
+
 /// ML-DSA signature with level information
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct MlDsaSignature {
@@ -100,12 +253,27 @@ pub struct MlDsaSignature {
     level: MlDsaLevel,
     /// Raw signature bytes
     bytes: Vec<u8>,
+    /// Length of the context string bound into this signature (0 if none)
+    context_len: usize,
 }
 
 impl MlDsaSignature {
-    /// Create a new signature from bytes
+    /// Create a new signature from bytes, with no application context
     pub(crate) fn new(level: MlDsaLevel, bytes: Vec<u8>) -> Self {
-        Self { level, bytes }
+        Self {
+            level,
+            bytes,
+            context_len: 0,
+        }
+    }
+
+    /// Create a new signature from bytes, recording the context length it was signed with
+    pub(crate) fn new_with_context(level: MlDsaLevel, bytes: Vec<u8>, context_len: usize) -> Self {
+        Self {
+            level,
+            bytes,
+            context_len,
+        }
     }
 
     /// Parse signature from bytes with level hint
@@ -121,6 +289,7 @@ impl MlDsaSignature {
         Ok(Self {
             level,
             bytes: bytes.to_vec(),
+            context_len: 0,
         })
     }
 
@@ -133,6 +302,89 @@ impl MlDsaSignature {
     pub fn level(&self) -> MlDsaLevel {
         self.level
     }
+
+    /// Length in bytes of the application context string bound into this signature
+    pub fn context_len(&self) -> usize {
+        self.context_len
+    }
+}
+
+/// Serde impls for [`MlDsaKeyPair`] and [`MlDsaSignature`]
+///
+/// **The serde form is not the wire/signature form.** A keypair serializes
+/// as its level and 32-byte seed only (the public key is regenerated from
+/// the seed on deserialize); a signature serializes as its level, raw
+/// bytes, and context length. Neither matches the 1312+ byte `public_key()`/`as_bytes()` layout,
+/// the same caveat secp256k1's docs give for its serde vs. wire encodings.
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use serde::de::Error as DeError;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::{MlDsaKeyPair, MlDsaSignature};
+    use crate::MlDsaLevel;
+
+    #[derive(Serialize, Deserialize)]
+    struct KeyPairRepr {
+        level: MlDsaLevel,
+        seed: [u8; 32],
+    }
+
+    impl Serialize for MlDsaKeyPair {
+        fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+            KeyPairRepr {
+                level: self.level,
+                seed: self.seed,
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for MlDsaKeyPair {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+            let repr = KeyPairRepr::deserialize(deserializer)?;
+
+            #[cfg(feature = "rustcrypto")]
+            {
+                crate::backend_rustcrypto::generate_keypair(repr.level, &repr.seed)
+                    .map_err(DeError::custom)
+            }
+            #[cfg(not(feature = "rustcrypto"))]
+            {
+                Err(DeError::custom(
+                    "no ML-DSA backend enabled to regenerate the public key",
+                ))
+            }
+        }
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct SignatureRepr {
+        level: MlDsaLevel,
+        bytes: Vec<u8>,
+        context_len: usize,
+    }
+
+    impl Serialize for MlDsaSignature {
+        fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+            SignatureRepr {
+                level: self.level,
+                bytes: self.bytes.clone(),
+                context_len: self.context_len,
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for MlDsaSignature {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+            let repr = SignatureRepr::deserialize(deserializer)?;
+            let mut signature =
+                MlDsaSignature::from_bytes(repr.level, &repr.bytes).map_err(DeError::custom)?;
+            signature.context_len = repr.context_len;
+            Ok(signature)
+        }
+    }
 }
 
 #[cfg(test)]
@@ -156,8 +408,142 @@ mod tests {
     fn test_signature_from_bytes_validates_size() {
         let result = MlDsaSignature::from_bytes(MlDsaLevel::Dsa44, &[0u8; 100]);
         assert!(result.is_err());
-        
+
         let result = MlDsaSignature::from_bytes(MlDsaLevel::Dsa44, &[0u8; 2420]);
         assert!(result.is_ok());
     }
+
+    #[cfg(feature = "rustcrypto")]
+    #[test]
+    fn test_keypair_bytes_roundtrip() {
+        let seed = [7u8; 32];
+        let keypair = backend_rustcrypto::generate_keypair(MlDsaLevel::Dsa44, &seed).unwrap();
+
+        let bytes = keypair.to_bytes();
+        let restored = MlDsaKeyPair::from_bytes(&bytes).unwrap();
+
+        assert_eq!(restored.level(), keypair.level());
+        assert_eq!(restored.seed(), keypair.seed());
+        assert_eq!(restored.public_key(), keypair.public_key());
+    }
+
+    #[cfg(feature = "rustcrypto")]
+    #[test]
+    fn test_keypair_base58_roundtrip() {
+        let seed = [7u8; 32];
+        let keypair = backend_rustcrypto::generate_keypair(MlDsaLevel::Dsa44, &seed).unwrap();
+
+        let encoded = keypair.to_base58_string();
+        let restored = MlDsaKeyPair::from_base58_string(&encoded).unwrap();
+
+        assert_eq!(restored.seed(), keypair.seed());
+    }
+
+    #[cfg(feature = "rustcrypto")]
+    #[test]
+    fn test_keypair_file_roundtrip() {
+        let seed = [7u8; 32];
+        let keypair = backend_rustcrypto::generate_keypair(MlDsaLevel::Dsa44, &seed).unwrap();
+
+        let mut path = std::env::temp_dir();
+        path.push("ml-dsa-bip39-test-keypair.json");
+        keypair.write_to_file(&path).unwrap();
+
+        let restored = MlDsaKeyPair::read_from_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(restored.seed(), keypair.seed());
+        assert_eq!(restored.public_key(), keypair.public_key());
+    }
+
+    #[cfg(feature = "rustcrypto")]
+    #[test]
+    fn test_keypair_from_bytes_rejects_wrong_length() {
+        let result = MlDsaKeyPair::from_bytes(&[0u8; 10]);
+        assert!(result.is_err());
+    }
+
+    #[cfg(feature = "rustcrypto")]
+    #[test]
+    fn test_keypair_from_bytes_rejects_public_key_mismatch() {
+        let keypair = backend_rustcrypto::generate_keypair(MlDsaLevel::Dsa44, &[7u8; 32]).unwrap();
+        let mut bytes = keypair.to_bytes();
+
+        // Flip a byte in the stored public key so it no longer matches
+        // what the seed re-derives.
+        let last = bytes.len() - 1;
+        bytes[last] ^= 0xff;
+
+        let result = MlDsaKeyPair::from_bytes(&bytes);
+        assert!(matches!(result, Err(Error::InvalidKeypair(_))));
+    }
+
+    #[cfg(feature = "rustcrypto")]
+    #[test]
+    fn test_sign_with_context_roundtrip() {
+        let keypair = backend_rustcrypto::generate_keypair(MlDsaLevel::Dsa44, &[9u8; 32]).unwrap();
+
+        let message = b"hello";
+        let context = b"domain-a";
+        let signature = keypair.sign_with_context(message, context).unwrap();
+
+        assert!(keypair.verify_with_context(message, context, &signature).unwrap());
+    }
+
+    #[cfg(feature = "rustcrypto")]
+    #[test]
+    fn test_sign_with_context_rejects_oversized_context() {
+        let keypair = backend_rustcrypto::generate_keypair(MlDsaLevel::Dsa44, &[9u8; 32]).unwrap();
+        let oversized_context = vec![0u8; MAX_CONTEXT_LEN + 1];
+
+        let result = keypair.sign_with_context(b"hello", &oversized_context);
+        assert!(matches!(result, Err(Error::InvalidContext(_))));
+    }
+
+    #[cfg(all(feature = "rustcrypto", feature = "serde"))]
+    #[test]
+    fn test_keypair_serde_roundtrip_uses_seed_not_public_key() {
+        let keypair = backend_rustcrypto::generate_keypair(MlDsaLevel::Dsa44, &[3u8; 32]).unwrap();
+
+        let json = serde_json::to_string(&keypair).unwrap();
+        // The serde form is the compact seed form, not the 1312+ byte public key.
+        assert!(json.len() < keypair.public_key().len());
+
+        let restored: MlDsaKeyPair = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.seed(), keypair.seed());
+        assert_eq!(restored.public_key(), keypair.public_key());
+    }
+
+    #[cfg(all(feature = "rustcrypto", feature = "serde"))]
+    #[test]
+    fn test_signature_serde_roundtrip() {
+        let keypair = backend_rustcrypto::generate_keypair(MlDsaLevel::Dsa44, &[3u8; 32]).unwrap();
+        let signature = keypair.sign_with_context(b"hello", b"ctx").unwrap();
+
+        let json = serde_json::to_string(&signature).unwrap();
+        let restored: MlDsaSignature = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored, signature);
+        assert_eq!(restored.context_len(), 3);
+    }
+
+    #[cfg(feature = "rustcrypto")]
+    #[test]
+    fn test_address_decodes_to_matching_prefix() {
+        let keypair = backend_rustcrypto::generate_keypair(MlDsaLevel::Dsa44, &[9u8; 32]).unwrap();
+        let address = keypair.address(7);
+
+        let (prefix, _account_id) = crate::address::decode_address(&address).unwrap();
+        assert_eq!(prefix, 7);
+    }
+
+    #[cfg(feature = "rustcrypto")]
+    #[test]
+    fn test_verify_with_context_rejects_length_mismatch() {
+        let keypair = backend_rustcrypto::generate_keypair(MlDsaLevel::Dsa44, &[9u8; 32]).unwrap();
+        let signature = keypair.sign_with_context(b"hello", b"domain-a").unwrap();
+
+        let result = keypair.verify_with_context(b"hello", b"domain-a-longer", &signature);
+        assert!(matches!(result, Err(Error::InvalidContext(_))));
+    }
 }